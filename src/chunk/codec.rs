@@ -0,0 +1,71 @@
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use super::{Chunk, ChunkError};
+use crate::chunk_type::ChunkType;
+
+/// A bounds-checked cursor over a byte slice, used to decode structured data
+/// out of a chunk's data field without hand-rolled `skip`/`take`/length math.
+pub struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Reader<'a> {
+        Reader { data, pos: 0 }
+    }
+
+    /// Bytes left to read.
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    /// Reads a big-endian `u32`, advancing the cursor by 4 bytes.
+    pub fn read_u32_be(&mut self) -> Result<u32, ChunkError> {
+        let bytes: [u8; 4] = self
+            .read_bytes(4)?
+            .try_into()
+            .expect("read_bytes(4) returns exactly 4 bytes");
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    /// Reads `n` bytes, advancing the cursor by `n`.
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], ChunkError> {
+        if self.remaining() < n {
+            return Err(ChunkError::TooShort);
+        }
+
+        let bytes = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+}
+
+/// Serializes a value into a chunk's data field.
+pub trait Encode {
+    fn encode(&self, out: &mut Vec<u8>);
+}
+
+/// Deserializes a value out of a chunk's data field.
+pub trait Decode: Sized {
+    fn decode(reader: &mut Reader) -> Result<Self, ChunkError>;
+}
+
+impl Chunk {
+    /// Builds a chunk whose data field is `payload` encoded via [`Encode`].
+    pub fn from_payload<T: Encode>(chunk_type: ChunkType, payload: &T) -> Chunk {
+        let mut data = vec![];
+        payload.encode(&mut data);
+        Chunk::new(chunk_type, data)
+    }
+
+    /// Decodes this chunk's data field as a `T` via [`Decode`].
+    pub fn decode_data<T: Decode>(&self) -> Result<T, ChunkError> {
+        let mut reader = Reader::new(&self.chunk_data);
+        T::decode(&mut reader)
+    }
+}
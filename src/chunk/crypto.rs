@@ -0,0 +1,47 @@
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use super::{Chunk, ChunkError};
+use crate::chunk_type::ChunkType;
+
+const NONCE_LEN: usize = 12;
+
+impl Chunk {
+    /// Encrypts `plaintext` with ChaCha20-Poly1305 under `key` and stores a
+    /// random nonce followed by the ciphertext (with its authentication tag)
+    /// as this chunk's data. The CRC is computed over those encrypted bytes,
+    /// so the chunk is still a valid PNG chunk on disk; the AEAD tag, not the
+    /// CRC, is what detects tampering.
+    pub fn new_encrypted(chunk_type: ChunkType, plaintext: &[u8], key: &[u8; 32]) -> Chunk {
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext)
+            .expect("encrypting an in-memory buffer cannot fail");
+
+        let mut data = nonce.to_vec();
+        data.extend(ciphertext);
+
+        Chunk::new(chunk_type, data)
+    }
+
+    /// Decrypts this chunk's data with ChaCha20-Poly1305 under `key`, assuming
+    /// it was produced by [`Chunk::new_encrypted`]. Fails if the data is too
+    /// short to hold a nonce, or if the AEAD tag does not authenticate (wrong
+    /// key, or the ciphertext was tampered with).
+    pub fn decrypt(&self, key: &[u8; 32]) -> Result<Vec<u8>, ChunkError> {
+        if self.chunk_data.len() < NONCE_LEN {
+            return Err(ChunkError::TooShort);
+        }
+
+        let (nonce_bytes, ciphertext) = self.chunk_data.split_at(NONCE_LEN);
+        let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+        let nonce = Nonce::from_slice(nonce_bytes);
+
+        cipher
+            .decrypt(nonce, ciphertext)
+            .map_err(|_| ChunkError::DecryptionFailed)
+    }
+}
@@ -0,0 +1,40 @@
+use core::fmt::Display;
+
+/// Errors produced while decoding, decrypting or writing a [`Chunk`](super::Chunk).
+#[derive(Debug)]
+pub enum ChunkError {
+    /// The input ended before all the bytes being read were available.
+    TooShort,
+    /// The declared length field does not match the number of data bytes present.
+    LengthMismatch,
+    /// The CRC stored in the chunk does not match the CRC computed over type + data.
+    CrcMismatch { expected: u32, actual: u32 },
+    /// The chunk data is not valid UTF-8 where a string was expected.
+    InvalidUtf8,
+    /// AEAD decryption failed: wrong key, or the ciphertext was tampered with.
+    DecryptionFailed,
+    /// The underlying reader or writer failed (the transport, not the chunk data).
+    Io,
+}
+
+impl Display for ChunkError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ChunkError::TooShort => write!(f, "input ended before the expected bytes were read"),
+            ChunkError::LengthMismatch => {
+                write!(f, "declared chunk length does not match the data present")
+            }
+            ChunkError::CrcMismatch { expected, actual } => {
+                write!(f, "CRC mismatch: expected {expected}, got {actual}")
+            }
+            ChunkError::InvalidUtf8 => write!(f, "chunk data is not valid UTF-8"),
+            ChunkError::DecryptionFailed => {
+                write!(f, "decryption failed: wrong key or tampered ciphertext")
+            }
+            ChunkError::Io => write!(f, "the underlying reader or writer failed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChunkError {}
@@ -0,0 +1,489 @@
+//! Chunk encoding/decoding. Builds under `#![no_std]` (this crate's `alloc`
+//! path) with the `std` feature disabled, using `embedded_io` in place of
+//! `std::io` for the streaming reader/writer; encryption (`crypto`) stays
+//! `std`-only since it needs an OS RNG.
+
+#[cfg(feature = "std")]
+use std::{fmt::Display, string::String, string::ToString, vec::Vec};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, string::ToString, vec, vec::Vec};
+#[cfg(not(feature = "std"))]
+use core::fmt::Display;
+
+use crate::chunk_type::ChunkType;
+
+mod codec;
+#[cfg(feature = "std")]
+mod crypto;
+mod error;
+mod reader;
+mod writer;
+
+pub use codec::{Decode, Encode, Reader};
+pub use error::ChunkError;
+pub use reader::ChunkReader;
+pub use writer::ChunkWriter;
+
+#[derive(Debug)]
+pub struct Chunk {
+    pub chunk_crc: u32,
+    pub chunk_type: ChunkType,
+    pub chunk_data: Vec<u8>,
+    pub length: u32,
+}
+
+impl Chunk {
+    fn new(chunk_type: ChunkType, data: Vec<u8>) -> Chunk {
+        let crc = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC)
+            .checksum(&chunk_type_and_data(&chunk_type, &data));
+
+        Chunk {
+            chunk_crc: crc,
+            chunk_type,
+            length: data.len() as u32,
+            chunk_data: data,
+        }
+    }
+
+    fn length(&self) -> u32 {
+        self.length
+    }
+
+    fn crc(&self) -> u32 {
+        self.chunk_crc
+    }
+
+    // Only exercised by tests right now (callers can read the `chunk_type`/
+    // `chunk_data` fields directly), but kept as accessors alongside
+    // `length`/`crc` for symmetry.
+    #[allow(dead_code)]
+    fn chunk_type(&self) -> ChunkType {
+        self.chunk_type.clone()
+    }
+
+    #[allow(dead_code)]
+    fn data_as_string(&self) -> Result<String, ChunkError> {
+        String::from_utf8(self.chunk_data.to_vec()).map_err(|_| ChunkError::InvalidUtf8)
+    }
+
+    /// Parses a chunk from its raw on-disk bytes, verifying the stored CRC
+    /// against one computed over the type and data. Use this for any input
+    /// that did not originate from this process (files, network, etc.).
+    pub fn from_bytes_verified(value: &[u8]) -> Result<Chunk, ChunkError> {
+        let (chunk, crc_data) = Self::parse(value)?;
+
+        let actual_crc = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC)
+            .checksum(&chunk_type_and_data(&chunk.chunk_type, &chunk.chunk_data));
+
+        if u32::from_be_bytes(crc_data) != actual_crc {
+            return Err(ChunkError::CrcMismatch {
+                expected: u32::from_be_bytes(crc_data),
+                actual: actual_crc,
+            });
+        }
+
+        Ok(chunk)
+    }
+
+    /// Parses a chunk from its raw on-disk bytes, trusting the stored CRC
+    /// without recomputing it. Only use this for data already known to be
+    /// well-formed, where skipping the checksum saves time.
+    pub fn from_bytes_trusted(value: &[u8]) -> Result<Chunk, ChunkError> {
+        let (chunk, _crc_data) = Self::parse(value)?;
+        Ok(chunk)
+    }
+
+    /// Splits raw bytes into a [`Chunk`] (with its stored CRC) and the raw CRC
+    /// bytes, without validating the CRC itself.
+    fn parse(value: &[u8]) -> Result<(Chunk, [u8; 4]), ChunkError> {
+        let mut reader = Reader::new(value);
+
+        let length = reader.read_u32_be()?;
+
+        let chunk_type: [u8; 4] = reader
+            .read_bytes(4)?
+            .try_into()
+            .expect("read_bytes(4) returns exactly 4 bytes");
+
+        let data = reader
+            .read_bytes(length as usize)
+            .map_err(|_| ChunkError::LengthMismatch)?;
+
+        let crc_data: [u8; 4] = reader
+            .read_bytes(4)?
+            .try_into()
+            .expect("read_bytes(4) returns exactly 4 bytes");
+
+        if reader.remaining() != 0 {
+            return Err(ChunkError::LengthMismatch);
+        }
+
+        let crc = u32::from_be_bytes(crc_data);
+
+        Ok((
+            Chunk {
+                chunk_crc: crc,
+                chunk_type: ChunkType(chunk_type),
+                chunk_data: data.to_vec(),
+                length,
+            },
+            crc_data,
+        ))
+    }
+}
+
+/// Concatenates a chunk type and its data for CRC computation.
+pub(crate) fn chunk_type_and_data(chunk_type: &ChunkType, chunk_data: &[u8]) -> Vec<u8> {
+    let mut chunk_type_data = vec![];
+    chunk_type_data.extend(chunk_type.bytes());
+    chunk_type_data.extend(chunk_data);
+    chunk_type_data
+}
+
+impl TryFrom<&Vec<u8>> for Chunk {
+    type Error = ChunkError;
+
+    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
+        Chunk::from_bytes_verified(value)
+    }
+}
+
+impl Display for Chunk {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let crc = self.crc();
+        let chunk_type = self.chunk_type.to_string();
+        let chunk_data = match core::str::from_utf8(&self.chunk_data) {
+            Ok(s) => s.to_string(),
+            Err(_) => self
+                .chunk_data
+                .iter()
+                .map(|b| format!("\\x{b:02x}"))
+                .collect(),
+        };
+
+        write!(
+            f,
+            "CRC: {}, Chunk type: {}, Chunk data: {}, Length: {}",
+            crc,
+            chunk_type,
+            chunk_data,
+            self.length()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn testing_chunk() -> Chunk {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656334;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        Chunk::try_from(chunk_data.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn test_new_chunk() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let data = "This is where your secret message will be!"
+            .as_bytes()
+            .to_vec();
+        let chunk = Chunk::new(chunk_type, data);
+        assert_eq!(chunk.length(), 42);
+        assert_eq!(chunk.crc(), 2882656334);
+    }
+
+    #[test]
+    fn test_chunk_length() {
+        let chunk = testing_chunk();
+        assert_eq!(chunk.length(), 42);
+    }
+
+    #[test]
+    fn test_chunk_type() {
+        let chunk = testing_chunk();
+        assert_eq!(chunk.chunk_type().to_string(), String::from("RuSt"));
+    }
+
+    #[test]
+    fn test_chunk_string() {
+        let chunk = testing_chunk();
+        let chunk_string = chunk.data_as_string().unwrap();
+        let expected_chunk_string = String::from("This is where your secret message will be!");
+        assert_eq!(chunk_string, expected_chunk_string);
+    }
+
+    #[test]
+    fn test_chunk_string_rejects_invalid_utf8() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, vec![0xff, 0xfe]);
+
+        assert!(matches!(
+            chunk.data_as_string(),
+            Err(ChunkError::InvalidUtf8)
+        ));
+    }
+
+    #[test]
+    fn test_chunk_crc() {
+        let chunk = testing_chunk();
+        assert_eq!(chunk.crc(), 2882656334);
+    }
+
+    #[test]
+    fn test_valid_chunk_from_bytes() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656334;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::try_from(chunk_data.as_ref()).unwrap();
+
+        let chunk_string = chunk.data_as_string().unwrap();
+        let expected_chunk_string = String::from("This is where your secret message will be!");
+
+        assert_eq!(chunk.length(), 42);
+        assert_eq!(chunk.chunk_type().to_string(), String::from("RuSt"));
+        assert_eq!(chunk_string, expected_chunk_string);
+        assert_eq!(chunk.crc(), 2882656334);
+    }
+
+    #[test]
+    fn test_invalid_chunk_from_bytes() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656333;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::try_from(chunk_data.as_ref());
+
+        assert!(chunk.is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_trusted_skips_crc_check() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let bogus_crc: u32 = 0;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(bogus_crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::from_bytes_trusted(&chunk_data).unwrap();
+        assert_eq!(chunk.crc(), bogus_crc);
+    }
+
+    #[test]
+    fn test_from_bytes_verified_rejects_bogus_crc() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let bogus_crc: u32 = 0;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(bogus_crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let err = Chunk::from_bytes_verified(&chunk_data).unwrap_err();
+        assert!(matches!(err, ChunkError::CrcMismatch { .. }));
+    }
+
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    impl Encode for Point {
+        fn encode(&self, out: &mut Vec<u8>) {
+            out.extend(self.x.to_be_bytes());
+            out.extend(self.y.to_be_bytes());
+        }
+    }
+
+    impl Decode for Point {
+        fn decode(reader: &mut Reader) -> Result<Self, ChunkError> {
+            Ok(Point {
+                x: reader.read_u32_be()?,
+                y: reader.read_u32_be()?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip_through_chunk() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let point = Point { x: 3, y: 9 };
+
+        let chunk = Chunk::from_payload(chunk_type, &point);
+        let decoded: Point = chunk.decode_data().unwrap();
+
+        assert_eq!(decoded.x, 3);
+        assert_eq!(decoded.y, 9);
+    }
+
+    // Unlike the `crypto` tests below, these only touch `reader`/`writer`/
+    // `codec`, which compile under both `std` and `embedded_io`-backed
+    // `no_std`, so they're the real no_std-reachable coverage for this module.
+    #[test]
+    fn test_chunk_reader_writer_roundtrip() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let a = Chunk::new(chunk_type.clone(), b"first message".to_vec());
+        let b = Chunk::new(chunk_type, b"second message".to_vec());
+
+        let mut bytes = vec![];
+        let mut writer = ChunkWriter::new(&mut bytes);
+        writer.write_chunk(&a).unwrap();
+        writer.write_chunk(&b).unwrap();
+
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        let first = reader.next().unwrap().unwrap();
+        let second = reader.next().unwrap().unwrap();
+
+        assert_eq!(first.chunk_data, a.chunk_data);
+        assert_eq!(second.chunk_data, b.chunk_data);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_detects_crc_mismatch() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, b"tampered message".to_vec());
+
+        let mut bytes = vec![];
+        ChunkWriter::new(&mut bytes).write_chunk(&chunk).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ChunkError::CrcMismatch { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_chunk_reader_detects_truncated_stream() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, b"truncated message".to_vec());
+
+        let mut bytes = vec![];
+        ChunkWriter::new(&mut bytes).write_chunk(&chunk).unwrap();
+        bytes.truncate(bytes.len() - 2);
+
+        let mut reader = ChunkReader::new(bytes.as_slice());
+        assert!(matches!(reader.next(), Some(Err(ChunkError::TooShort))));
+    }
+
+    #[test]
+    fn test_reader_too_short() {
+        let mut reader = Reader::new(&[0u8, 1]);
+        assert!(matches!(reader.read_u32_be(), Err(ChunkError::TooShort)));
+    }
+
+    // `crypto` (and therefore `Chunk::new_encrypted`/`decrypt`) only exists
+    // when the `std` feature is on (see `mod crypto` in `chunk/mod.rs`), so
+    // these two tests must not compile under `--no-default-features`.
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_encrypt_decrypt_roundtrip() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let key = [7u8; 32];
+        let plaintext = b"This is where your secret message will be!";
+
+        let chunk = Chunk::new_encrypted(chunk_type, plaintext, &key);
+        let decrypted = chunk.decrypt(&key).unwrap();
+
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_decrypt_with_wrong_key_fails() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let key = [7u8; 32];
+        let wrong_key = [9u8; 32];
+        let plaintext = b"This is where your secret message will be!";
+
+        let chunk = Chunk::new_encrypted(chunk_type, plaintext, &key);
+
+        assert!(matches!(
+            chunk.decrypt(&wrong_key),
+            Err(ChunkError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn test_display_falls_back_to_hex_for_non_utf8_data() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let chunk = Chunk::new(chunk_type, vec![0xff, 0xfe]);
+
+        let rendered = format!("{chunk}");
+        assert!(rendered.contains("\\xff\\xfe"));
+    }
+
+    #[test]
+    pub fn test_chunk_trait_impls() {
+        let data_length: u32 = 42;
+        let chunk_type = "RuSt".as_bytes();
+        let message_bytes = "This is where your secret message will be!".as_bytes();
+        let crc: u32 = 2882656334;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk: Chunk = TryFrom::try_from(chunk_data.as_ref()).unwrap();
+
+        let _chunk_string = format!("{}", chunk);
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(feature = "std")]
+use std::io::Read;
+
+#[cfg(not(feature = "std"))]
+use embedded_io::Read;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use crc::{Crc, CRC_32_ISO_HDLC};
+
+use crate::chunk_type::ChunkType;
+
+use super::{chunk_type_and_data, Chunk, ChunkError};
+
+/// Max bytes pulled per `read()` call while streaming in a chunk's data. Only
+/// `Read::read` is used here (not `read_exact`/`take`/`read_to_end`, which
+/// `embedded_io::Read` doesn't provide), so this is also what bounds how much
+/// a corrupt or malicious length field can over-allocate: the buffer only
+/// grows as bytes actually arrive, never ahead of the stream.
+const READ_BLOCK_LEN: usize = 8 * 1024;
+
+/// Reads a sequence of [`Chunk`]s from any [`Read`] source, one chunk at a time,
+/// without buffering the whole stream in memory.
+///
+/// Each call to `next` reads exactly one chunk's length, type, data and CRC, so
+/// multi-gigabyte images can be processed with constant memory overhead.
+pub struct ChunkReader<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> ChunkReader<R> {
+    pub fn new(reader: R) -> ChunkReader<R> {
+        ChunkReader { reader }
+    }
+
+    fn read_exact_bytes(&mut self, buf: &mut [u8]) -> Result<(), ChunkError> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            let n = self
+                .reader
+                .read(&mut buf[filled..])
+                .map_err(|_| ChunkError::Io)?;
+            if n == 0 {
+                return Err(ChunkError::TooShort);
+            }
+            filled += n;
+        }
+        Ok(())
+    }
+
+    fn read_chunk(&mut self) -> Result<Option<Chunk>, ChunkError> {
+        let mut length_bytes = [0u8; 4];
+        match self.reader.read(&mut length_bytes[..1]) {
+            Ok(0) => return Ok(None),
+            Ok(_) => {}
+            Err(_) => return Err(ChunkError::Io),
+        }
+        self.read_exact_bytes(&mut length_bytes[1..])?;
+        let length = u32::from_be_bytes(length_bytes);
+
+        let mut chunk_type_bytes = [0u8; 4];
+        self.read_exact_bytes(&mut chunk_type_bytes)?;
+
+        // `length` comes straight off the wire and is not yet trusted, so we
+        // must not zero-allocate a buffer of that size up front (a corrupt or
+        // malicious header could claim e.g. u32::MAX and exhaust memory
+        // before a single byte is confirmed to exist). Reading in bounded
+        // blocks means a short stream never allocates more than it delivers.
+        let mut chunk_data = vec![];
+        let mut remaining = length as usize;
+        let mut block = [0u8; READ_BLOCK_LEN];
+        while remaining > 0 {
+            let want = remaining.min(block.len());
+            let n = self
+                .reader
+                .read(&mut block[..want])
+                .map_err(|_| ChunkError::Io)?;
+            if n == 0 {
+                break;
+            }
+            chunk_data.extend_from_slice(&block[..n]);
+            remaining -= n;
+        }
+        if chunk_data.len() != length as usize {
+            return Err(ChunkError::TooShort);
+        }
+
+        let mut crc_bytes = [0u8; 4];
+        self.read_exact_bytes(&mut crc_bytes)?;
+        let crc = u32::from_be_bytes(crc_bytes);
+
+        let actual_crc = Crc::<u32>::new(&CRC_32_ISO_HDLC)
+            .checksum(&chunk_type_and_data(&ChunkType(chunk_type_bytes), &chunk_data));
+        if crc != actual_crc {
+            return Err(ChunkError::CrcMismatch {
+                expected: crc,
+                actual: actual_crc,
+            });
+        }
+
+        Ok(Some(Chunk {
+            chunk_crc: crc,
+            chunk_type: ChunkType(chunk_type_bytes),
+            chunk_data,
+            length,
+        }))
+    }
+}
+
+impl<R: Read> Iterator for ChunkReader<R> {
+    type Item = Result<Chunk, ChunkError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_chunk() {
+            Ok(Some(chunk)) => Some(Ok(chunk)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
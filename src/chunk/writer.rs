@@ -0,0 +1,34 @@
+#[cfg(feature = "std")]
+use std::io::Write;
+
+#[cfg(not(feature = "std"))]
+use embedded_io::Write;
+
+use super::{Chunk, ChunkError};
+
+/// Writes [`Chunk`]s to any [`Write`] sink in the on-disk length+type+data+CRC
+/// layout, without building an intermediate `Vec` for the whole chunk.
+pub struct ChunkWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> ChunkWriter<W> {
+    pub fn new(writer: W) -> ChunkWriter<W> {
+        ChunkWriter { writer }
+    }
+
+    pub fn write_chunk(&mut self, chunk: &Chunk) -> Result<(), ChunkError> {
+        self.writer
+            .write_all(&chunk.length.to_be_bytes())
+            .map_err(|_| ChunkError::Io)?;
+        self.writer
+            .write_all(&chunk.chunk_type.bytes())
+            .map_err(|_| ChunkError::Io)?;
+        self.writer
+            .write_all(&chunk.chunk_data)
+            .map_err(|_| ChunkError::Io)?;
+        self.writer
+            .write_all(&chunk.chunk_crc.to_be_bytes())
+            .map_err(|_| ChunkError::Io)
+    }
+}
@@ -0,0 +1,97 @@
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+/// A 4-byte PNG chunk type code (e.g. `IHDR`, `IDAT`, or a custom ancillary
+/// type such as `RuSt`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkType(pub [u8; 4]);
+
+impl ChunkType {
+    pub fn bytes(&self) -> [u8; 4] {
+        self.0
+    }
+}
+
+impl FromStr for ChunkType {
+    type Err = ChunkTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+
+        let array: [u8; 4] = bytes
+            .try_into()
+            .map_err(|_| ChunkTypeError::InvalidLength)?;
+
+        if !array.iter().all(|b| b.is_ascii_alphabetic()) {
+            return Err(ChunkTypeError::InvalidCharacter);
+        }
+
+        Ok(ChunkType(array))
+    }
+}
+
+impl Display for ChunkType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = core::str::from_utf8(&self.0).map_err(|_| fmt::Error)?;
+        write!(f, "{s}")
+    }
+}
+
+/// Errors produced while parsing a [`ChunkType`] from a string.
+#[derive(Debug)]
+pub enum ChunkTypeError {
+    /// The input was not exactly 4 bytes long.
+    InvalidLength,
+    /// A byte was not an ASCII letter.
+    InvalidCharacter,
+}
+
+impl Display for ChunkTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChunkTypeError::InvalidLength => write!(f, "chunk type must be exactly 4 bytes"),
+            ChunkTypeError::InvalidCharacter => {
+                write!(f, "chunk type bytes must be ASCII letters")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChunkTypeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    use std::string::ToString;
+
+    #[test]
+    fn test_chunk_type_from_str() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        assert_eq!(chunk_type.bytes(), *b"RuSt");
+    }
+
+    #[test]
+    fn test_chunk_type_display() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        assert_eq!(chunk_type.to_string(), "RuSt");
+    }
+
+    #[test]
+    fn test_chunk_type_rejects_wrong_length() {
+        assert!(matches!(
+            ChunkType::from_str("Rust!"),
+            Err(ChunkTypeError::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn test_chunk_type_rejects_non_alphabetic() {
+        assert!(matches!(
+            ChunkType::from_str("Ru5t"),
+            Err(ChunkTypeError::InvalidCharacter)
+        ));
+    }
+}
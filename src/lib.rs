@@ -0,0 +1,10 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+pub mod chunk;
+pub mod chunk_type;